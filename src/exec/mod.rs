@@ -0,0 +1,249 @@
+//! Execution of a command for each search result (`--exec`) or once for the
+//! whole batch of results (`--exec-batch`).
+//!
+//! Every spawned child is placed into its own process group and tracked in
+//! [`process_group`], so that an interrupt can tear down the entire subprocess
+//! tree instead of leaving orphans behind.
+
+pub mod process_group;
+
+use std::ffi::OsString;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::Receiver;
+use std::sync::{Arc, Mutex};
+
+use crate::error::print_error;
+use crate::exit_codes::{merge_exitcodes, ExitCode};
+use crate::walk::WorkerResult;
+
+/// Whether a command is run once per path or once for the whole batch.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ExecutionMode {
+    OneForEach,
+    Batch,
+}
+
+/// A single placeholder token understood inside an `--exec` argument.
+#[derive(Clone, Copy)]
+enum Token {
+    /// `{}` — the full path of the search result.
+    Placeholder,
+    /// `{.}` — the path without its file extension.
+    NoExt,
+    /// `{/}` — the basename (file name) of the path.
+    Basename,
+    /// `{//}` — the parent directory of the path.
+    Parent,
+    /// `{/.}` — the basename without its file extension.
+    BasenameNoExt,
+}
+
+/// A single argument of the command, either literal text or a placeholder.
+enum ArgumentTemplate {
+    Text(String),
+    Placeholder(Token),
+}
+
+/// The full command template built from the user's `--exec`/`--exec-batch`
+/// argument. Constructed by the command-line layer.
+pub struct CommandSet {
+    mode: ExecutionMode,
+    args: Vec<ArgumentTemplate>,
+}
+
+impl CommandSet {
+    /// Whether the results should be handed to a single batched invocation.
+    pub fn in_batch_mode(&self) -> bool {
+        self.mode == ExecutionMode::Batch
+    }
+
+    /// Expand one argument template against a single `input` path.
+    fn expand(template: &Token, input: &Path) -> OsString {
+        let expanded: PathBuf = match template {
+            Token::Placeholder => input.to_path_buf(),
+            Token::NoExt => input.with_extension(""),
+            Token::Basename => input.file_name().map(PathBuf::from).unwrap_or_default(),
+            Token::Parent => input.parent().map(PathBuf::from).unwrap_or_default(),
+            Token::BasenameNoExt => input
+                .file_stem()
+                .map(PathBuf::from)
+                .unwrap_or_default(),
+        };
+        expanded.into_os_string()
+    }
+
+    /// Build a [`Command`] for the given inputs. In one-for-each mode a single
+    /// input is supplied; in batch mode every placeholder expands to all inputs.
+    fn build_command(&self, inputs: &[PathBuf]) -> Option<Command> {
+        let mut args = self.args.iter();
+        let program = match args.next() {
+            Some(ArgumentTemplate::Text(text)) => text,
+            // The first argument is always the program name, never a placeholder.
+            _ => return None,
+        };
+
+        let mut command = Command::new(program);
+        for arg in args {
+            match arg {
+                ArgumentTemplate::Text(text) => {
+                    command.arg(text);
+                }
+                ArgumentTemplate::Placeholder(token) => {
+                    for input in inputs {
+                        command.arg(Self::expand(token, input));
+                    }
+                }
+            }
+        }
+        Some(command)
+    }
+
+    /// Run the command once for a single search result.
+    pub fn execute(&self, input: &Path, out_perm: Arc<Mutex<()>>) -> ExitCode {
+        match self.build_command(std::slice::from_ref(&input.to_path_buf())) {
+            Some(mut command) => run_captured(&mut command, &out_perm),
+            None => ExitCode::GeneralError,
+        }
+    }
+
+    /// Run the command once for the whole batch of search results.
+    pub fn execute_batch(&self, inputs: &[PathBuf]) -> ExitCode {
+        match self.build_command(inputs) {
+            Some(mut command) => run_inherited(&mut command),
+            None => ExitCode::GeneralError,
+        }
+    }
+}
+
+/// Spawn `command` in its own process group and register it in
+/// [`process_group`], so that an interrupt signals it (and its descendants) as
+/// part of the tree.
+fn spawn_tracked(command: &mut Command) -> io::Result<std::process::Child> {
+    // Put the child (and everything it spawns) into a fresh process group.
+    process_group::add_to_new_group(command);
+    let child = command.spawn()?;
+    process_group::register(child.id());
+    Ok(child)
+}
+
+/// Run the command for a single result, capturing its output and replaying it
+/// while holding `out_perm` so that concurrent `-x` jobs do not interleave on
+/// the console.
+fn run_captured(command: &mut Command, out_perm: &Arc<Mutex<()>>) -> ExitCode {
+    command.stdin(Stdio::inherit());
+    command.stdout(Stdio::piped());
+    command.stderr(Stdio::piped());
+
+    let child = match spawn_tracked(command) {
+        Ok(child) => child,
+        Err(why) => {
+            print_error(format!("Problem while executing command: {}", why));
+            return ExitCode::GeneralError;
+        }
+    };
+
+    let pid = child.id();
+    let result = child.wait_with_output();
+    process_group::unregister(pid);
+
+    match result {
+        Ok(output) => {
+            let _lock = out_perm.lock().unwrap();
+            let _ = io::stdout().write_all(&output.stdout);
+            let _ = io::stderr().write_all(&output.stderr);
+            if output.status.success() {
+                ExitCode::Success
+            } else {
+                ExitCode::GeneralError
+            }
+        }
+        Err(why) => {
+            print_error(format!("Problem while executing command: {}", why));
+            ExitCode::GeneralError
+        }
+    }
+}
+
+/// Run the command once for the whole batch (`--exec-batch`).
+///
+/// This is a single invocation, so there is nothing to interleave; the child
+/// inherits our stdio directly, keeping interactive/TUI children attached to the
+/// terminal and letting their output stream as it is produced.
+fn run_inherited(command: &mut Command) -> ExitCode {
+    let mut child = match spawn_tracked(command) {
+        Ok(child) => child,
+        Err(why) => {
+            print_error(format!("Problem while executing command: {}", why));
+            return ExitCode::GeneralError;
+        }
+    };
+
+    let pid = child.id();
+    let result = child.wait();
+    process_group::unregister(pid);
+
+    match result {
+        Ok(status) if status.success() => ExitCode::Success,
+        Ok(_) => ExitCode::GeneralError,
+        Err(why) => {
+            print_error(format!("Problem while executing command: {}", why));
+            ExitCode::GeneralError
+        }
+    }
+}
+
+/// Receive search results and execute the command once for each of them.
+pub fn job(
+    rx: Arc<Mutex<Receiver<WorkerResult>>>,
+    cmd: Arc<CommandSet>,
+    out_perm: Arc<Mutex<()>>,
+    show_filesystem_errors: bool,
+) -> ExitCode {
+    let mut results: Vec<ExitCode> = Vec::new();
+    loop {
+        // Acquire the lock only long enough to receive the next result.
+        let lock = rx.lock().unwrap();
+        let path = match lock.recv() {
+            Ok(WorkerResult::Entry(path, _)) => path,
+            Ok(WorkerResult::Error(err)) => {
+                if show_filesystem_errors {
+                    print_error(err.to_string());
+                }
+                continue;
+            }
+            Err(_) => break,
+        };
+        drop(lock);
+
+        results.push(cmd.execute(&path, Arc::clone(&out_perm)));
+    }
+    merge_exitcodes(&results)
+}
+
+/// Collect every search result and execute the command once for all of them.
+pub fn batch(
+    rx: Receiver<WorkerResult>,
+    cmd: &CommandSet,
+    show_filesystem_errors: bool,
+) -> ExitCode {
+    let inputs: Vec<PathBuf> = rx
+        .into_iter()
+        .filter_map(|worker_result| match worker_result {
+            WorkerResult::Entry(path, _) => Some(path),
+            WorkerResult::Error(err) => {
+                if show_filesystem_errors {
+                    print_error(err.to_string());
+                }
+                None
+            }
+        })
+        .collect();
+
+    if inputs.is_empty() {
+        return ExitCode::Success;
+    }
+
+    cmd.execute_batch(&inputs)
+}
@@ -0,0 +1,112 @@
+//! Tracking of the process groups spawned by `--exec` so that an interrupt can
+//! tear down the entire subprocess tree instead of leaving orphans behind.
+//!
+//! Each child started by [`job`](super::job)/[`batch`](super::batch) is placed
+//! into a fresh process group and registered here. When a SIGINT/SIGTERM is
+//! received while in exec mode, [`terminate_all`] signals every tracked group,
+//! first politely with `SIGTERM` and then, after a short grace period, with
+//! `SIGKILL`.
+
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use once_cell::sync::Lazy;
+
+use crate::exit_codes::ExitCode;
+
+/// How long to wait after `SIGTERM` before escalating to `SIGKILL`.
+#[cfg(unix)]
+const GRACE_PERIOD: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// The identifiers of every process group that is currently alive. On Unix this
+/// is the group id (equal to the leader's pid); on Windows it is the child pid.
+static GROUPS: Lazy<Mutex<Vec<u32>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+/// Configure `command` so that its child starts in a new process group, letting
+/// us signal the child and all of its descendants at once.
+pub fn add_to_new_group(command: &mut Command) {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        // SAFETY: `setpgid` is async-signal-safe and only touches the group of
+        // the just-forked child.
+        unsafe {
+            command.pre_exec(|| {
+                libc::setpgid(0, 0);
+                Ok(())
+            });
+        }
+    }
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        // CREATE_NEW_PROCESS_GROUP
+        command.creation_flags(0x0000_0200);
+    }
+}
+
+/// Register a freshly spawned child's group so it can be signalled on interrupt.
+pub fn register(pid: u32) {
+    GROUPS.lock().unwrap().push(pid);
+}
+
+/// Forget a group once its child has been reaped.
+pub fn unregister(pid: u32) {
+    GROUPS.lock().unwrap().retain(|&p| p != pid);
+}
+
+/// Signal every tracked process group, terminating the whole subprocess tree.
+pub fn terminate_all() {
+    let groups = std::mem::take(&mut *GROUPS.lock().unwrap());
+
+    #[cfg(unix)]
+    {
+        for &pgid in &groups {
+            // Negative pid targets the entire process group.
+            unsafe {
+                libc::kill(-(pgid as i32), libc::SIGTERM);
+            }
+        }
+        if !groups.is_empty() {
+            std::thread::spawn(move || {
+                std::thread::sleep(GRACE_PERIOD);
+                for &pgid in &groups {
+                    unsafe {
+                        libc::kill(-(pgid as i32), libc::SIGKILL);
+                    }
+                }
+            });
+        }
+    }
+
+    #[cfg(windows)]
+    {
+        // CTRL_BREAK_EVENT is delivered to every process attached to the group.
+        for &pid in &groups {
+            unsafe {
+                windows_sys::Win32::System::Console::GenerateConsoleCtrlEvent(1, pid);
+            }
+        }
+    }
+}
+
+/// Install the interrupt handler used while running in exec mode.
+///
+/// The first interrupt flips `wants_to_quit` (which stops the walker from
+/// feeding new paths) and tears down every running child group; a second
+/// interrupt exits immediately.
+pub fn install_handler(wants_to_quit: &Arc<AtomicBool>) {
+    let wants_to_quit = Arc::clone(wants_to_quit);
+    let result = ctrlc::set_handler(move || {
+        if wants_to_quit.swap(true, Ordering::Relaxed) {
+            // Interrupt pressed twice: give up on a graceful shutdown.
+            std::process::exit(ExitCode::KilledBySigint.into());
+        }
+        terminate_all();
+    });
+    if result.is_err() {
+        // A handler was already installed; nothing more to do.
+    }
+}
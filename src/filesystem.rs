@@ -0,0 +1,54 @@
+/// Raise the soft `RLIMIT_NOFILE` limit as far as the hard limit allows.
+///
+/// Every `--exec` job keeps a child process (and all of the file descriptors it
+/// opens) alive while it runs, so a high `--threads` count can easily exhaust
+/// the default soft limit of 256 descriptors on macOS/BSD and surface spurious
+/// "Too many open files" errors. We therefore bump the soft limit up to the
+/// hard limit once, before the command thread pool is spawned.
+///
+/// On Darwin the new soft limit is also clamped to `kern.maxfilesperproc`,
+/// since `setrlimit` refuses anything larger; the hard limit itself is left
+/// unchanged. The soft limit is only ever raised,
+/// never lowered, and any syscall failure is ignored so that unprivileged runs
+/// simply keep the limit they already have.
+#[cfg(unix)]
+pub fn raise_soft_fd_limit() {
+    // SAFETY: `getrlimit`/`setrlimit`/`sysctl` only read from and write to the
+    // stack variables whose addresses we pass them.
+    unsafe {
+        let mut limit = std::mem::MaybeUninit::<libc::rlimit>::uninit();
+        if libc::getrlimit(libc::RLIMIT_NOFILE, limit.as_mut_ptr()) != 0 {
+            return;
+        }
+        let mut limit = limit.assume_init();
+
+        #[cfg(target_os = "macos")]
+        let new_soft = {
+            let mut max_files: libc::c_int = 0;
+            let mut size = std::mem::size_of::<libc::c_int>();
+            let mut mib = [libc::CTL_KERN, libc::KERN_MAXFILESPERPROC];
+            if libc::sysctl(
+                mib.as_mut_ptr(),
+                mib.len() as libc::c_uint,
+                &mut max_files as *mut _ as *mut libc::c_void,
+                &mut size,
+                std::ptr::null_mut(),
+                0,
+            ) == 0
+                && max_files > 0
+            {
+                std::cmp::min(max_files as libc::rlim_t, limit.rlim_max)
+            } else {
+                limit.rlim_max
+            }
+        };
+
+        #[cfg(not(target_os = "macos"))]
+        let new_soft = limit.rlim_max;
+
+        if new_soft > limit.rlim_cur {
+            limit.rlim_cur = new_soft;
+            let _ = libc::setrlimit(libc::RLIMIT_NOFILE, &limit);
+        }
+    }
+}
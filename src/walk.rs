@@ -33,8 +33,12 @@ enum ReceiverMode {
 }
 
 /// The Worker threads can result in a valid entry having PathBuf or an error.
+///
+/// A matched entry also carries the [`Metadata`] that was already fetched while
+/// applying the size/time filters (if any), so that the sorting receiver can
+/// order results by size or modification time without a second `stat`.
 pub enum WorkerResult {
-    Entry(PathBuf),
+    Entry(PathBuf, Option<Metadata>),
     Error(ignore::Error),
 }
 
@@ -47,6 +51,60 @@ pub const MAX_BUFFER_LENGTH: usize = 1000;
 /// jobs in parallel from a given command line and the discovered paths. Otherwise, each
 /// path will simply be written to standard output.
 pub fn scan(path_vec: &[PathBuf], pattern: Arc<Regex>, config: Arc<Options>) -> Result<ExitCode> {
+    let wants_to_quit = Arc::new(AtomicBool::new(false));
+
+    // Install the interrupt handler once, before any traversal: `scan_once` can
+    // run more than once (in `--watch` mode) and `ctrlc` refuses a second
+    // handler.
+    #[cfg(not(target_os = "wasi"))]
+    install_interrupt_handler(&config, &wants_to_quit);
+
+    let walker = build_walker(path_vec, &config)?;
+    let exit_code = scan_once(walker, Arc::clone(&pattern), &config, &wants_to_quit)?;
+
+    // In watch mode we keep the process alive after the initial traversal and
+    // handle the paths that change below one of the search roots. Each changed
+    // path goes through `filter_entry` and the same receiver pipeline as the
+    // initial traversal (see `watch`).
+    #[cfg(not(target_os = "wasi"))]
+    if config.watch {
+        return watch(path_vec, &pattern, &config, &wants_to_quit);
+    }
+
+    if wants_to_quit.load(Ordering::Relaxed) {
+        Ok(ExitCode::KilledBySigint)
+    } else {
+        Ok(exit_code)
+    }
+}
+
+/// Install the handler that reacts to a Ctrl-C / SIGINT.
+///
+/// In exec mode this stops the walker *and* tears down the whole subprocess
+/// tree; otherwise it simply flips `wants_to_quit` so the coloured output can be
+/// reset before exiting. It must be called at most once per process, since
+/// `ctrlc` rejects a second handler.
+#[cfg(not(target_os = "wasi"))]
+fn install_interrupt_handler(config: &Arc<Options>, wants_to_quit: &Arc<AtomicBool>) {
+    if config.command.is_some() {
+        exec::process_group::install_handler(wants_to_quit);
+    } else if config.ls_colors.is_some() {
+        let wq = Arc::clone(wants_to_quit);
+        // Ignore a failure here: a handler may already have been installed.
+        let _ = ctrlc::set_handler(move || {
+            if wq.load(Ordering::Relaxed) {
+                // Ctrl-C has been pressed twice, exit NOW
+                process::exit(ExitCode::KilledBySigint.into());
+            } else {
+                wq.store(true, Ordering::Relaxed);
+            }
+        });
+    }
+}
+
+/// Build a [`WalkBuilder`] for the given roots, applying all of the ignore and
+/// traversal settings from `config`.
+fn build_walker(path_vec: &[PathBuf], config: &Arc<Options>) -> Result<WalkBuilder> {
     let mut path_iter = path_vec.iter();
     let first_path_buf = path_iter
         .next()
@@ -127,42 +185,44 @@ pub fn scan(path_vec: &[PathBuf], pattern: Arc<Regex>, config: Arc<Options>) ->
         walker.add(path_entry.as_path());
     }
 
-    let wants_to_quit = Arc::new(AtomicBool::new(false));
+    Ok(walker)
+}
+
+/// Perform a single traversal with the given `walker`, streaming the matches to
+/// the output or handing them to the `--exec` thread pool.
+fn scan_once(
+    mut walker: WalkBuilder,
+    pattern: Arc<Regex>,
+    config: &Arc<Options>,
+    wants_to_quit: &Arc<AtomicBool>,
+) -> Result<ExitCode> {
+    // Raise the soft file-descriptor limit before building the exec thread pool,
+    // so that running `--exec` with many `--threads` does not exhaust the default
+    // limit with the descriptors held open by the spawned child processes.
+    #[cfg(unix)]
+    {
+        if config.command.is_some() {
+            filesystem::raise_soft_fd_limit();
+        }
+    }
 
     // multithreaded
     #[cfg(not(target_os = "wasi"))]
     {
         let parallel_walker = walker.threads(config.threads).build_parallel();
 
-        if config.ls_colors.is_some() && config.command.is_none() {
-            let wq = Arc::clone(&wants_to_quit);
-            ctrlc::set_handler(move || {
-                if wq.load(Ordering::Relaxed) {
-                    // Ctrl-C has been pressed twice, exit NOW
-                    process::exit(ExitCode::KilledBySigint.into());
-                } else {
-                    wq.store(true, Ordering::Relaxed);
-                }
-            })
-            .unwrap();
-        }
-
         let (tx, rx) = channel();
 
         // Spawn the thread that receives all results through the channel.
-        let receiver_thread = spawn_receiver(&config, &wants_to_quit, rx);
+        let receiver_thread = spawn_receiver(config, wants_to_quit, rx);
 
         // Spawn the sender threads.
-        spawn_senders(&config, &wants_to_quit, pattern, parallel_walker, tx);
+        spawn_senders(config, wants_to_quit, pattern, parallel_walker, tx);
 
         // Wait for the receiver thread to print out all results.
         let exit_code = receiver_thread.join().unwrap();
 
-        if wants_to_quit.load(Ordering::Relaxed) {
-            Ok(ExitCode::KilledBySigint)
-        } else {
-            Ok(exit_code)
-        }
+        Ok(exit_code)
     }
 
     // possibly single-threaded
@@ -170,7 +230,7 @@ pub fn scan(path_vec: &[PathBuf], pattern: Arc<Regex>, config: Arc<Options>) ->
     {
         anyhow::ensure!(config.command.is_none(), "Executing commands not supported on WASI");
         let stdout = io::stdout();
-        let mut acceptor = EntryPrinter::new(&config, &wants_to_quit, &stdout);
+        let mut acceptor = EntryPrinter::new(config, wants_to_quit, &stdout);
         let config_filter = config.clone();
         let pattern_filter = pattern.clone();
         walker.filter_entry(move |entry_o| {
@@ -179,8 +239,8 @@ pub fn scan(path_vec: &[PathBuf], pattern: Arc<Regex>, config: Arc<Options>) ->
         });
         for entry_o in walker.build() {
                 let (next, item) = match entry_o {
-                    Ok(r) =>  filter_entry(&config, &*pattern, Ok(&r)),
-                    Err(e) => filter_entry(&config, &*pattern, Err(e)),
+                    Ok(r) =>  filter_entry(config, &*pattern, Ok(&r)),
+                    Err(e) => filter_entry(config, &*pattern, Err(e)),
                 };
                 if let Some(item) = item {
                     if !acceptor.accept(item) {
@@ -196,6 +256,133 @@ pub fn scan(path_vec: &[PathBuf], pattern: Arc<Regex>, config: Arc<Options>) ->
     }
 }
 
+/// Keep watching every search root recursively and re-run the search whenever a
+/// matching path below one of them changes.
+///
+/// Filesystem events are coalesced over a short window (`max_buffer_time`) so
+/// that a burst of edits triggers a single run. Only the paths that actually
+/// changed are handled: each is fed through [`filter_entry`] — so the pattern,
+/// extensions, file types, size/time constraints and the ignore configuration
+/// still apply — and the resulting matches are dispatched through the same
+/// [`spawn_receiver`] pipeline as the initial traversal, printing through
+/// `EntryPrinter` or executing through `exec::job`/`exec::batch`.
+#[cfg(not(target_os = "wasi"))]
+fn watch(
+    path_vec: &[PathBuf],
+    pattern: &Arc<Regex>,
+    config: &Arc<Options>,
+    wants_to_quit: &Arc<AtomicBool>,
+) -> Result<ExitCode> {
+    use std::sync::mpsc::RecvTimeoutError;
+
+    use notify::{RecursiveMode, Watcher};
+
+    let (tx, rx) = channel();
+    let mut watcher = notify::recommended_watcher(move |res| {
+        if let Ok(event) = res {
+            // A send error only means the main thread has already gone away.
+            let _ = tx.send(event);
+        }
+    })
+    .map_err(|e| anyhow!("Failed to initialize file system watcher: {}", e))?;
+
+    for path in path_vec {
+        watcher
+            .watch(path.as_path(), RecursiveMode::Recursive)
+            .map_err(|e| anyhow!("Failed to watch '{}': {}", path.to_string_lossy(), e))?;
+    }
+
+    // How long to keep collecting events before coalescing them into one run.
+    let coalesce_window = config
+        .max_buffer_time
+        .unwrap_or_else(|| time::Duration::from_millis(100));
+
+    loop {
+        // Wait for the first event, but poll so that an interrupt (which flips
+        // `wants_to_quit` from the signal handler) is noticed promptly instead
+        // of only on the next filesystem event.
+        let mut changed: Vec<PathBuf> = loop {
+            if wants_to_quit.load(Ordering::Relaxed) {
+                return Ok(ExitCode::KilledBySigint);
+            }
+            match rx.recv_timeout(time::Duration::from_millis(100)) {
+                Ok(event) => break event.paths,
+                Err(RecvTimeoutError::Timeout) => continue,
+                Err(RecvTimeoutError::Disconnected) => return Ok(ExitCode::Success),
+            }
+        };
+
+        // Keep draining the queue until the event stream has been quiet for
+        // `coalesce_window`, so a burst of edits coalesces into one run.
+        while let Ok(event) = rx.recv_timeout(coalesce_window) {
+            changed.extend(event.paths);
+        }
+
+        let results = matching_changes(&changed, pattern, config)?;
+        if results.is_empty() {
+            continue;
+        }
+
+        // Dispatch the changed matches through the normal receiver pipeline, so
+        // print mode and `--exec`/`--exec-batch` behave exactly as usual.
+        let (tx, rx) = channel();
+        let receiver_thread = spawn_receiver(config, wants_to_quit, rx);
+        for item in results {
+            if tx.send(item).is_err() {
+                break;
+            }
+        }
+        drop(tx);
+        receiver_thread.join().unwrap();
+    }
+}
+
+/// Run the changed paths through [`filter_entry`] and collect the ones that
+/// match.
+///
+/// The paths are grouped by parent directory and each parent is walked once, so
+/// that the walker's ignore configuration (`.gitignore`/`.fdignore`, hidden
+/// files, excludes and custom ignore files) is applied, before the remaining
+/// constraints are checked by `filter_entry`. Those rules are checked against
+/// the changed entry itself only: the parent is the root of its walk, and the
+/// walker never filters a root, so a change inside an ignored or hidden
+/// directory is still reported.
+#[cfg(not(target_os = "wasi"))]
+fn matching_changes(
+    changed: &[PathBuf],
+    pattern: &Arc<Regex>,
+    config: &Arc<Options>,
+) -> Result<Vec<WorkerResult>> {
+    use std::collections::HashSet;
+
+    let changed: HashSet<&Path> = changed
+        .iter()
+        .filter(|p| p.exists())
+        .map(PathBuf::as_path)
+        .collect();
+
+    let mut parents: Vec<&Path> = changed.iter().filter_map(|p| p.parent()).collect();
+    parents.sort_unstable();
+    parents.dedup();
+
+    let mut results = Vec::new();
+    for parent in parents {
+        let mut walker = build_walker(std::slice::from_ref(&parent.to_path_buf()), config)?;
+        for entry in walker.max_depth(Some(1)).build() {
+            let entry = match entry {
+                Ok(entry) if changed.contains(entry.path()) => entry,
+                _ => continue,
+            };
+            let (_next, item) = filter_entry(config, &**pattern, Ok(&entry));
+            if let Some(item) = item {
+                results.push(item);
+            }
+        }
+    }
+
+    Ok(results)
+}
+
 fn spawn_receiver(
     config: &Arc<Options>,
     wants_to_quit: &Arc<AtomicBool>,
@@ -241,6 +428,8 @@ fn spawn_receiver(
 
                 merge_exitcodes(&results)
             }
+        } else if config.sort.is_some() {
+            sort_and_print(&config, &wants_to_quit, rx)
         } else {
             let stdout = io::stdout();
             let mut acceptor = EntryPrinter::new(&config, &wants_to_quit, &stdout);
@@ -256,6 +445,70 @@ fn spawn_receiver(
     })
 }
 
+/// Buffer every result to completion, order it by the requested sort key and
+/// only then print it.
+///
+/// Unlike the default streaming path this never prints incrementally, so it is
+/// only selected when a `--sort` key is actually requested; throughput of the
+/// unsorted default is therefore unaffected.
+fn sort_and_print(
+    config: &Arc<Options>,
+    wants_to_quit: &Arc<AtomicBool>,
+    rx: Receiver<WorkerResult>,
+) -> ExitCode {
+    use crate::options::SortBy;
+
+    let mut entries: Vec<(PathBuf, Option<Metadata>)> = Vec::new();
+    for worker_result in rx {
+        match worker_result {
+            WorkerResult::Entry(path, metadata) => entries.push((path, metadata)),
+            WorkerResult::Error(err) => {
+                if config.show_filesystem_errors {
+                    print_error(err.to_string());
+                }
+            }
+        }
+    }
+
+    // Lazily `stat` entries whose metadata was not already fetched by a filter.
+    // `sort_by_cached_key` computes the key exactly once per entry, so an entry
+    // that still needs a `stat` is never re-`stat`-ed during the sort.
+    let metadata_of = |path: &Path, cached: &Option<Metadata>| -> Option<Metadata> {
+        cached.clone().or_else(|| path.metadata().ok())
+    };
+
+    match config.sort.as_ref().expect("sort key is set") {
+        SortBy::Path => entries.sort_by(|a, b| a.0.cmp(&b.0)),
+        SortBy::Extension => entries.sort_by(|a, b| {
+            a.0.extension()
+                .cmp(&b.0.extension())
+                .then_with(|| a.0.cmp(&b.0))
+        }),
+        SortBy::Size => entries.sort_by_cached_key(|(path, md)| {
+            metadata_of(path, md).map(|m| m.len()).unwrap_or(0)
+        }),
+        SortBy::MTime => entries.sort_by_cached_key(|(path, md)| {
+            metadata_of(path, md).and_then(|m| m.modified().ok())
+        }),
+    }
+
+    if config.sort_reverse {
+        entries.reverse();
+    }
+
+    if let Some(max_results) = config.max_results {
+        entries.truncate(max_results);
+    }
+
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+    for (path, _) in &entries {
+        output::print_entry(&mut stdout, path, config, wants_to_quit);
+    }
+
+    ExitCode::Success
+}
+
 struct EntryPrinter<'a> {
     config: &'a Arc<Options>,
     start: time::Instant,
@@ -286,7 +539,9 @@ impl<'a> EntryPrinter<'a> {
             .max_buffer_time
             .unwrap_or_else(|| time::Duration::from_millis(100));
         match worker_result {
-            WorkerResult::Entry(value) => {
+            // The streaming path only needs the path; any metadata carried for
+            // the sorting receiver is simply dropped here.
+            WorkerResult::Entry(value, _) => {
                 match self.mode {
                     ReceiverMode::Buffering => {
                         self.buffer.push(value);
@@ -417,6 +672,16 @@ fn spawn_senders(
     });
 }
 
+/// Fetch (and cache) the metadata for `path`, so that the various metadata
+/// filters share a single `stat` call and can hand the result on to the result
+/// consumer.
+fn fetch_metadata<'a>(path: &Path, cache: &'a mut Option<Metadata>) -> Option<&'a Metadata> {
+    if cache.is_none() {
+        *cache = path.metadata().ok();
+    }
+    cache.as_ref()
+}
+
 fn filter_entry(
     config: &Arc<Options>,
     pattern: &Regex,
@@ -519,15 +784,17 @@ fn filter_entry(
         }
     }
 
+    // Metadata is fetched at most once and then threaded through the filters
+    // below as well as on to the result, so the sorting receiver can order by
+    // size or mtime without `stat`-ing a second time.
+    let mut metadata: Option<Metadata> = None;
+
     #[cfg(unix)]
     {
         if let Some(ref owner_constraint) = config.owner_constraint {
-            if let Ok(ref metadata) = entry_path.metadata() {
-                if !owner_constraint.matches(&metadata) {
-                    return empty_ok;
-                }
-            } else {
-                return empty_ok;
+            match fetch_metadata(entry_path, &mut metadata) {
+                Some(md) if owner_constraint.matches(md) => {}
+                _ => return empty_ok,
             }
         }
     }
@@ -535,8 +802,8 @@ fn filter_entry(
     // Filter out unwanted sizes if it is a file and we have been given size constraints.
     if !config.size_constraints.is_empty() {
         if entry_path.is_file() {
-            if let Ok(metadata) = entry_path.metadata() {
-                let file_size = metadata.len();
+            if let Some(md) = fetch_metadata(entry_path, &mut metadata) {
+                let file_size = md.len();
                 if config
                     .size_constraints
                     .iter()
@@ -555,8 +822,8 @@ fn filter_entry(
     // Filter out unwanted modification times
     if !config.time_constraints.is_empty() {
         let mut matched = false;
-        if let Ok(metadata) = entry_path.metadata() {
-            if let Ok(modified) = metadata.modified() {
+        if let Some(md) = fetch_metadata(entry_path, &mut metadata) {
+            if let Ok(modified) = md.modified() {
                 matched = config
                     .time_constraints
                     .iter()
@@ -574,5 +841,5 @@ fn filter_entry(
         false => ignore::WalkState::Continue,
     };
 
-    (skip, Some(WorkerResult::Entry(entry_path.to_owned())))
+    (skip, Some(WorkerResult::Entry(entry_path.to_owned(), metadata)))
 }